@@ -1,19 +1,30 @@
 //! R[ing B]uffer is a simple overwriting ring buffer implementation.
 //! A RingBuffer allocates it's memory once at creation on the heap.
-//! The RingBuffer implements std::io::Read and std::io::Write for interacting with the buffer.
+//! `RingBuffer<T>` is generic over its element type `T: Copy + Default`, so it can back
+//! byte streams just as well as queues of samples, frames, or other fixed-size records.
+//! The core `read`/`write` operations are inherent methods available with or without the
+//! standard library; the RingBuffer additionally implements std::io::Read and std::io::Write
+//! when `T = u8` and the `std` feature is enabled, for interacting with the buffer as a byte stream.
 //! Any size buffer can be written to the RingBuffer, just note that only the capacity of the RingBuffer will be retained.
 //! Reading data from the buffer will move the tail index, so the read data is essentially dropped.
 //! If one wants to get a copy of the data on the form of a vector, a helper function are available to easily acquire one.
+//! A RingBuffer also has a target capacity, separate from its current capacity: in non-overwrite
+//! mode a write that would otherwise block is free to grow the backing buffer up to the target,
+//! and `shrink_to_target` releases that growth back down once the buffer has drained.
 //!
 //! # Features
-//! - `sync` - A Sync implementation of the RingBuffer.
+//! - `std` (default) - Enables `std::io::Read`/`Write`/`BufRead` for `RingBuffer<u8>`. Disable for
+//!   `no_std` + `alloc` environments; the inherent `read`/`write` methods remain available either way.
+//! - `sync` - A Sync implementation of the RingBuffer. Requires `std`.
+//! - `spsc` - A lock-free Producer/Consumer split of the RingBuffer for the single-writer,
+//!   single-reader case. Requires `std`.
 //!
 //! # Usage
 //! ## Create a new RingBuffer with a specific capacity
 //! ```rust
 //! use ruffer::RingBuffer;
 //!
-//! let buffer = RingBuffer::with_capacity(1024);
+//! let buffer: RingBuffer<u8> = RingBuffer::with_capacity(1024);
 //! ```
 //! ## Write data to the buffer
 //! ```rust
@@ -34,43 +45,135 @@
 //! ## Read data from the buffer
 //! ```rust
 //! use ruffer::RingBuffer;
-//! use std::io::Read;
 //!
 //! let mut buffer = RingBuffer::with_capacity(1024);
 //! // ... use ringbuffer ...
 //! let read_data = &mut [0u8; 32];
-//! match buffer.read(read_data) {
-//!   Ok(bytes) => {
-//!     println!("read {} bytes from buffer", bytes);
-//!   }
-//!   Err(e) => {
-//!     println!("{}", e);
-//!   }
-//! }
+//! let bytes = buffer.read(read_data);
+//! println!("read {} bytes from buffer", bytes);
+//! ```
+//! ## Copy data out of the buffer without an intermediate allocation
+//! This relies on the `std::io::Read`/`Write` impls, so it requires the (default-on) `std`
+//! feature.
+//! ```rust
+//! # #[cfg(feature = "std")]
+//! # {
+//! use ruffer::RingBuffer;
+//! use std::io::{copy, Write};
+//!
+//! let mut buffer = RingBuffer::with_capacity(1024);
+//! buffer.write("Test data buffer".as_bytes()).unwrap();
+//! let mut sink: Vec<u8> = Vec::new();
+//! let bytes = copy(&mut buffer, &mut sink).unwrap();
+//! println!("copied {} bytes out of the buffer", bytes);
+//! # }
 //! ```
 //!
 //! # Release Notes
+//! ## v1.6.0
+//! - Added a target capacity, separate from the current capacity: `target_capacity`/
+//!   `set_target_capacity` record it, `limits` reports occupancy/free space/capacity in one
+//!   shot, a non-overwrite `write` now grows the backing buffer toward the target instead of
+//!   returning `WouldBlock` while under it, and `shrink_to_target` releases that growth back
+//!   down once the buffer has drained.
+//! ## v1.5.0
+//! - Implemented `std::io::BufRead` for `RingBuffer<u8>`: `fill_buf` hands out the contiguous
+//!   readable slice straight out of the ring's own buffer, so `read_until`/`lines` consumers can
+//!   parse off of it directly. `io::copy` does not use `fill_buf` here; it still reads through
+//!   the `Read` impl into its own intermediate buffer.
+//! ## v1.4.0
+//! - Added zero-copy contiguous slice access: `as_slices`/`free_slices` expose the ring's two
+//!   physical regions directly, and `advance_write`/`consume` commit elements produced/consumed
+//!   through them, for callers that want to read or write in place without an intermediate copy.
+//! ## v1.3.0
+//! - Added `no_std` + `alloc` support behind the (default-on) `std` feature. The `sync` and
+//!   `spsc` modules and the `std::io::Read`/`Write` impls now require `std`; the generic
+//!   `write`/`read` inherent methods (renamed from `push`/the old `io::Read` body) work in
+//!   both modes; `write` returns a crate-local `Error` instead of `std::io::Error`, and `read`
+//!   returns the number of elements read directly.
+//! ## v1.2.0
+//! - `RingBuffer` is now generic over its element type: `RingBuffer<T>` with `T: Copy + Default`.
+//!   `std::io::Read`/`Write` remain implemented only for `RingBuffer<u8>`. `pop_bytes` is
+//!   renamed to `pop` and a generic `push` replaces the byte-specific internals of `write`.
+//! ## v1.1.0
+//! - Added `RingBuffer::split` behind the `spsc` feature, for lock-free single-producer/
+//!   single-consumer use.
 //! ## v1.0.3
 //! - Added the ability to turn overwriting off. This may be helpful for Producer/Consumer type use cases.
 //! ## v1.0.2 and Previous
 //! - These were the initial commits of Ruffer. I messed up some stuff around the docs etc, so my bad...
 
-#[cfg(feature = "sync")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+#[cfg(all(feature = "sync", feature = "std"))]
 pub mod sync;
 
+#[cfg(all(feature = "spsc", feature = "std"))]
+pub mod spsc;
+
 const DEFAULT_CAPACITY: usize = 10240;
 
-pub struct RingBuffer {
-    buffer: Vec<u8>,
-    capacity: usize,
+/// Errors produced by the `no_std`-compatible inherent `write` method.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The RingBuffer is full and overwrite is disabled, so nothing could be written.
+    WouldBlock,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::WouldBlock => write!(f, "the RingBuffer is full"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+#[cfg(feature = "std")]
+impl From<Error> for std::io::Error {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::WouldBlock => std::io::ErrorKind::WouldBlock.into(),
+        }
+    }
+}
+
+pub struct RingBuffer<T> {
+    pub(crate) buffer: Vec<T>,
+    pub(crate) capacity: usize,
+    target_capacity: usize,
     len: usize,
-    head: usize,
-    tail: usize,
+    pub(crate) head: usize,
+    pub(crate) tail: usize,
     overwrite: bool,
 }
 
+/// A snapshot of a RingBuffer's occupancy and free space, returned by [`RingBuffer::limits`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// Number of elements currently stored.
+    pub occupied: usize,
+    /// Number of elements that can still be written at the current capacity, before
+    /// growth toward the target capacity would kick in.
+    pub free: usize,
+    /// The buffer's current backing capacity.
+    pub capacity: usize,
+}
+
 // Static Impls
-impl RingBuffer {
+// Note: this is `T: Copy + Default` rather than just `T: Copy`. `Default` is what lets
+// `with_capacity`/`resize` pre-fill the backing `Vec<T>` and `to_vec` pre-fill the Vec it
+// returns, so it's a deliberate tightening of the element-type bound, not an
+// oversight; it does rule out `Copy` types with no sensible default value.
+impl<T: Copy + Default> RingBuffer<T> {
     /// Create a new RingBuffer with the default capacity
     ///
     /// # Returns
@@ -82,14 +185,15 @@ impl RingBuffer {
     /// Create a new RingBuffer with a specified capacity
     ///
     /// # Parameters
-    /// - **size** - capacity in bytes
+    /// - **size** - capacity in elements
     ///
     /// # Returns
     /// An empty RingBuffer instance with the default capacity
     pub fn with_capacity(size: usize) -> Self {
         RingBuffer {
-            buffer: vec![0u8; size],
+            buffer: vec![T::default(); size],
             capacity: size,
+            target_capacity: size,
             len: 0,
             head: 0,
             tail: 0,
@@ -99,11 +203,14 @@ impl RingBuffer {
 }
 
 // Member Impls
-impl RingBuffer {
+// `empty()` below is this type's `is_empty()`; it predates the `len_without_is_empty` lint
+// and renaming it would be a breaking API change, so the lint is silenced instead.
+#[allow(clippy::len_without_is_empty)]
+impl<T: Copy + Default> RingBuffer<T> {
     /// Acquire the capacity of the RingBuffer
     ///
     /// # Returns
-    /// The capacity of the RingBuffer in bytes
+    /// The capacity of the RingBuffer in elements
     pub fn capacity(&self) -> usize {
         self.capacity
     }
@@ -124,6 +231,59 @@ impl RingBuffer {
         self.len
     }
 
+    /// Acquire a snapshot of the RingBuffer's occupancy, free space, and current capacity
+    /// in one shot
+    ///
+    /// # Returns
+    /// The current [`Limits`]
+    pub fn limits(&self) -> Limits {
+        Limits {
+            occupied: self.len,
+            free: self.capacity - self.len,
+            capacity: self.capacity,
+        }
+    }
+
+    /// Acquire the target capacity of the RingBuffer
+    ///
+    /// # Returns
+    /// The target capacity in elements. Defaults to the capacity the RingBuffer was
+    /// created with.
+    pub fn target_capacity(&self) -> usize {
+        self.target_capacity
+    }
+
+    /// Set the target capacity of the RingBuffer
+    ///
+    /// This does not resize the backing buffer by itself. Once set, a [`RingBuffer::write`]
+    /// in non-overwrite mode that would otherwise block is free to grow the backing buffer
+    /// up to this size instead, and [`RingBuffer::shrink_to_target`] will release capacity
+    /// back down to this size once the buffer is drained.
+    ///
+    /// # Parameters
+    /// - **target** - the new target capacity, in elements
+    pub fn set_target_capacity(&mut self, target: usize) {
+        self.target_capacity = target;
+    }
+
+    /// Shrink the backing buffer back toward the target capacity, if the RingBuffer is
+    /// currently empty and above its target capacity
+    ///
+    /// This is an opt-in step: capacity grown by [`RingBuffer::write`] is kept around
+    /// until a caller calls this to release it, so bursty producer/consumer use cases can
+    /// grow once and drain many times without repeated reallocation.
+    ///
+    /// # Returns
+    /// **true** if the RingBuffer was shrunk, **false** if it was left unchanged
+    pub fn shrink_to_target(&mut self) -> bool {
+        if self.len == 0 && self.capacity > self.target_capacity {
+            self.resize(self.target_capacity);
+            true
+        } else {
+            false
+        }
+    }
+
     /// Acquire the overwrite mode state
     ///
     /// # Returns
@@ -145,30 +305,177 @@ impl RingBuffer {
     ///
     /// # Returns
     /// The contents of the RingBuffer in a newly allocated Vec
-    pub fn to_vec(&self) -> Vec<u8> {
-        let mut ret = vec![0u8; self.len];
-        let slice = ret.as_mut_slice();
-        for i in 0..self.len {
-            slice[i] = self.buffer[(self.tail + i) % self.capacity]
+    pub fn to_vec(&self) -> Vec<T> {
+        let mut ret = vec![T::default(); self.len];
+        for (i, slot) in ret.iter_mut().enumerate() {
+            *slot = self.buffer[(self.tail + i) % self.capacity];
         }
         ret
     }
 
-    /// Pop bytes from the RingBuffer
-    /// This function doesn't actually remove any data, just moves the head index and adjusts the data length essentially removing the data
+    /// Pop elements from the RingBuffer
+    /// This function doesn't actually remove any data, just moves the tail index and adjusts the data length essentially removing the data
     ///
     /// # Parameters
-    /// - **num** - number of bytes to pop
+    /// - **num** - number of elements to pop
     ///
     /// # Returns
-    /// The capacity of the RingBuffer
-    pub fn pop_bytes(&mut self, num: usize) -> usize {
-        let actual_num = std::cmp::min(self.len, num);
+    /// The number of elements actually popped
+    pub fn pop(&mut self, num: usize) -> usize {
+        let actual_num = core::cmp::min(self.len, num);
         self.len -= actual_num;
-        self.tail = (self.tail + actual_num) % self.capacity;
+        if self.capacity > 0 {
+            self.tail = (self.tail + actual_num) % self.capacity;
+        }
         actual_num
     }
 
+    /// Write elements into the RingBuffer
+    /// Any size slice can be written, just note that only the capacity of the RingBuffer will be retained,
+    /// unless overwrite is disabled, in which case only the free space will be filled.
+    ///
+    /// This is an inherent method so it works whether or not the `std` feature is enabled; the
+    /// `std::io::Write` impl for `RingBuffer<u8>` is a thin wrapper around it.
+    ///
+    /// # Parameters
+    /// - **buf** - the elements to write
+    ///
+    /// # Returns
+    /// The number of elements actually written, or `Error::WouldBlock` if overwrite is disabled
+    /// and the RingBuffer is already full (and cannot grow any further toward its target
+    /// capacity, see [`RingBuffer::set_target_capacity`])
+    pub fn write(&mut self, buf: &[T]) -> Result<usize, Error> {
+        if !self.overwrite {
+            let required = self.len + buf.len();
+            if required > self.capacity && self.capacity < self.target_capacity {
+                self.resize(core::cmp::min(self.target_capacity, required));
+            }
+            if self.len == self.capacity {
+                return Err(Error::WouldBlock);
+            }
+        }
+        // A RingBuffer shrunk to a zero target capacity has no slots to index into,
+        // even in overwrite mode, so there is nothing to do but report that nothing
+        // was written.
+        if self.capacity == 0 {
+            return Ok(0);
+        }
+        let num_elements = match self.overwrite {
+            true => buf.len(),
+            false => core::cmp::min(self.capacity - self.len, buf.len()),
+        };
+        let buffer = self.buffer.as_mut_slice();
+        for element in buf.iter().take(num_elements) {
+            buffer[self.head] = *element;
+            if self.head == self.tail && self.len > 0 {
+                self.tail = (self.tail + 1) % self.capacity;
+            } else {
+                self.len += 1;
+            }
+            self.head = (self.head + 1) % self.capacity;
+        }
+        Ok(num_elements)
+    }
+
+    /// Read elements out of the RingBuffer into `buf`, removing them from the RingBuffer.
+    ///
+    /// This is an inherent method so it works whether or not the `std` feature is enabled; the
+    /// `std::io::Read` impl for `RingBuffer<u8>` is a thin wrapper around it.
+    ///
+    /// # Parameters
+    /// - **buf** - the slice to read into
+    ///
+    /// # Returns
+    /// The number of elements actually read. `0` if the RingBuffer is empty.
+    pub fn read(&mut self, buf: &mut [T]) -> usize {
+        let mut num_elements = 0;
+        if self.len != 0 {
+            let buffer = self.buffer.as_slice();
+            num_elements = core::cmp::min(self.len, buf.len());
+            for slot in buf.iter_mut().take(num_elements) {
+                *slot = buffer[self.tail];
+                self.tail = (self.tail + 1) % self.capacity;
+                self.len -= 1;
+            }
+        }
+        num_elements
+    }
+
+    /// Acquire the readable contents of the RingBuffer as up to two contiguous slices,
+    /// without copying or removing any data.
+    ///
+    /// The first slice starts at the tail; the second is non-empty only when the readable
+    /// region wraps around the end of the backing buffer. Concatenating the two, in order,
+    /// yields the same elements as [`RingBuffer::to_vec`] without the allocation. Pair this
+    /// with [`RingBuffer::consume`] to read in place, e.g. to feed a hasher or another
+    /// writer directly out of the ring.
+    ///
+    /// # Returns
+    /// The readable region as `(first, second)`
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        if self.tail + self.len <= self.capacity {
+            (&self.buffer[self.tail..self.tail + self.len], &[])
+        } else {
+            let (start, end) = self.buffer.split_at(self.tail);
+            (end, &start[..self.tail + self.len - self.capacity])
+        }
+    }
+
+    /// Acquire the free space of the RingBuffer as up to two contiguous mutable slices,
+    /// without writing or reserving any data.
+    ///
+    /// The first slice starts at the head; the second is non-empty only when the free
+    /// region wraps around the end of the backing buffer. Fill these directly and then
+    /// call [`RingBuffer::advance_write`] to commit the elements actually produced, e.g.
+    /// to have a reader fill the ring's own storage with no scratch allocation.
+    ///
+    /// # Returns
+    /// The free region as `(first, second)`
+    pub fn free_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let free = self.capacity - self.len;
+        if self.head + free <= self.capacity {
+            let head = self.head;
+            (&mut self.buffer[head..head + free], &mut [])
+        } else {
+            let split = self.head;
+            let (start, end) = self.buffer.split_at_mut(split);
+            let wrapped = split + free - self.capacity;
+            (end, &mut start[..wrapped])
+        }
+    }
+
+    /// Commit elements already written directly into the slices returned by
+    /// [`RingBuffer::free_slices`].
+    ///
+    /// # Parameters
+    /// - **num** - number of elements produced; clamped to the available free space
+    ///
+    /// # Returns
+    /// The number of elements actually committed
+    pub fn advance_write(&mut self, num: usize) -> usize {
+        let actual_num = core::cmp::min(self.capacity - self.len, num);
+        if self.capacity > 0 {
+            self.head = (self.head + actual_num) % self.capacity;
+        }
+        self.len += actual_num;
+        actual_num
+    }
+
+    /// Commit elements already read directly out of the slices returned by
+    /// [`RingBuffer::as_slices`].
+    ///
+    /// This is an alias for [`RingBuffer::pop`], named to mirror `as_slices`/`free_slices`/
+    /// `advance_write` for callers driving I/O straight out of the ring's own storage.
+    ///
+    /// # Parameters
+    /// - **num** - number of elements consumed
+    ///
+    /// # Returns
+    /// The number of elements actually consumed
+    pub fn consume(&mut self, num: usize) -> usize {
+        self.pop(num)
+    }
+
     /// Resize the RingBuffer
     /// This function internally uses the to_vec function to simplify the logic, meaning there is a new allocation of the internal buffer
     ///
@@ -180,37 +487,22 @@ impl RingBuffer {
     pub fn resize(&mut self, new_size: usize) {
         if self.capacity != new_size {
             if self.len > new_size {
-                self.pop_bytes(self.len - new_size);
+                self.pop(self.len - new_size);
             }
-            self.buffer = self.to_vec();
-            self.len = self.buffer.len();
-            self.head = self.len % new_size;
+            let mut buffer = self.to_vec();
+            buffer.resize(new_size, T::default());
+            self.buffer = buffer;
+            self.head = if new_size == 0 { 0 } else { self.len % new_size };
             self.tail = 0;
             self.capacity = new_size;
         }
     }
 }
 
-impl std::io::Write for RingBuffer {
+#[cfg(feature = "std")]
+impl std::io::Write for RingBuffer<u8> {
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-        let buffer = self.buffer.as_mut_slice();
-        if !self.overwrite && self.len == self.capacity {
-            return Err(std::io::ErrorKind::WouldBlock.into());
-        }
-        let num_bytes = match self.overwrite {
-            true => buf.len(),
-            false => std::cmp::min(self.capacity - self.len, buf.len()),
-        };
-        for i in 0..num_bytes {
-            buffer[self.head] = buf[i];
-            if self.head == self.tail && self.len > 0 {
-                self.tail = (self.tail + 1) % self.capacity;
-            } else {
-                self.len += 1;
-            }
-            self.head = (self.head + 1) % self.capacity;
-        }
-        Ok(num_bytes)
+        RingBuffer::write(self, buf).map_err(Into::into)
     }
 
     fn flush(&mut self) -> std::io::Result<()> {
@@ -218,23 +510,25 @@ impl std::io::Write for RingBuffer {
     }
 }
 
-impl std::io::Read for RingBuffer {
+#[cfg(feature = "std")]
+impl std::io::Read for RingBuffer<u8> {
     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
-        let mut bytes = 0;
-        if self.len != 0 {
-            let buffer = self.buffer.as_slice();
-            bytes = std::cmp::min(self.len, buf.len());
-            for i in 0..bytes {
-                buf[i] = buffer[self.tail];
-                self.tail = (self.tail + 1) % self.capacity;
-                self.len -= 1;
-            }
-        }
-        Ok(bytes)
+        Ok(RingBuffer::read(self, buf))
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::BufRead for RingBuffer<u8> {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        Ok(self.as_slices().0)
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pop(amt);
     }
 }
 
-impl Default for RingBuffer {
+impl<T: Copy + Default> Default for RingBuffer<T> {
     fn default() -> Self {
         Self::new()
     }
@@ -242,25 +536,26 @@ impl Default for RingBuffer {
 
 #[cfg(test)]
 mod tests {
-    use std::io::{Read, Write};
+    #[cfg(feature = "std")]
+    use std::io::BufRead;
 
     use super::*;
 
     #[test]
     fn create_ringbuffer_default() {
-        let ruffer = RingBuffer::new();
+        let ruffer: RingBuffer<u8> = RingBuffer::new();
         assert_eq!(ruffer.capacity(), DEFAULT_CAPACITY);
     }
 
     #[test]
     fn create_ringbuffer_with_capacity() {
-        let ruffer = RingBuffer::with_capacity(1024);
+        let ruffer: RingBuffer<u8> = RingBuffer::with_capacity(1024);
         assert_eq!(ruffer.capacity(), 1024);
     }
 
     #[test]
     fn is_empty() {
-        let ruffer = RingBuffer::with_capacity(1024);
+        let ruffer: RingBuffer<u8> = RingBuffer::with_capacity(1024);
         assert!(ruffer.empty());
     }
 
@@ -285,29 +580,37 @@ mod tests {
     }
 
     #[test]
-    fn pop_bytes_nowrap() {
-        let mut ruffer = RingBuffer::with_capacity(16);
+    fn pop_nowrap() {
+        let mut ruffer: RingBuffer<u8> = RingBuffer::with_capacity(16);
         let write_data = "data".as_bytes();
         let read_data = &mut [0u8; 16];
         assert!(ruffer.write(write_data).is_ok());
-        assert_eq!(ruffer.pop_bytes(2), 2);
+        assert_eq!(ruffer.pop(2), 2);
         assert_eq!(ruffer.len(), 2);
-        assert!(ruffer.read(read_data).is_ok());
+        assert_eq!(ruffer.read(read_data), 2);
         assert_eq!(read_data[0..2].to_vec(), write_data[2..4].to_vec())
     }
 
     #[test]
-    fn pop_bytes_wrap() {
-        let mut ruffer = RingBuffer::with_capacity(4);
+    fn pop_wrap() {
+        let mut ruffer: RingBuffer<u8> = RingBuffer::with_capacity(4);
         let write_data = "data123".as_bytes();
         let read_data = &mut [0u8; 16];
         assert!(ruffer.write(write_data).is_ok());
-        assert_eq!(ruffer.pop_bytes(2), 2);
+        assert_eq!(ruffer.pop(2), 2);
         assert_eq!(ruffer.len(), 2);
-        assert!(ruffer.read(read_data).is_ok());
+        assert_eq!(ruffer.read(read_data), 2);
         assert_eq!(read_data[0..2].to_vec(), write_data[5..7].to_vec())
     }
 
+    #[test]
+    fn write_and_pop_generic_element() {
+        let mut ruffer: RingBuffer<u32> = RingBuffer::with_capacity(4);
+        assert_eq!(ruffer.write(&[1, 2, 3, 4]).unwrap(), 4);
+        assert_eq!(ruffer.pop(2), 2);
+        assert_eq!(ruffer.to_vec(), vec![3, 4]);
+    }
+
     #[test]
     fn write_less_than_capacity() {
         let mut ruffer = RingBuffer::with_capacity(16);
@@ -345,9 +648,7 @@ mod tests {
     fn read_empty() {
         let mut ruffer = RingBuffer::with_capacity(16);
         let data = &mut [0u8; 16];
-        let res = ruffer.read(data);
-        assert!(res.is_ok());
-        assert_eq!(res.unwrap(), 0);
+        assert_eq!(ruffer.read(data), 0);
     }
 
     #[test]
@@ -357,9 +658,7 @@ mod tests {
         let read_data = &mut [0u8; 16];
 
         assert!(ruffer.write(write_data).is_ok());
-        let res = ruffer.read(read_data);
-        assert!(res.is_ok());
-        assert_eq!(res.unwrap(), 4);
+        assert_eq!(ruffer.read(read_data), 4);
         assert_eq!(&read_data[0..4], write_data);
     }
 
@@ -370,9 +669,7 @@ mod tests {
         let read_data = &mut [0u8; 16];
 
         assert!(ruffer.write(write_data).is_ok());
-        let res = ruffer.read(read_data);
-        assert!(res.is_ok());
-        assert_eq!(res.unwrap(), 4);
+        assert_eq!(ruffer.read(read_data), 4);
         assert_eq!(&read_data[0..4], write_data);
     }
 
@@ -383,9 +680,7 @@ mod tests {
         let read_data = &mut [0u8; 16];
 
         assert!(ruffer.write(write_data).is_ok());
-        let res = ruffer.read(read_data);
-        assert!(res.is_ok());
-        assert_eq!(res.unwrap(), 4);
+        assert_eq!(ruffer.read(read_data), 4);
         assert_eq!(&read_data[0..4], &write_data[7..11]);
     }
 
@@ -397,9 +692,7 @@ mod tests {
 
         assert!(ruffer.write(write_data).is_ok());
         ruffer.resize(4);
-        let res = ruffer.read(read_data);
-        assert!(res.is_ok());
-        assert_eq!(res.unwrap(), 4);
+        assert_eq!(ruffer.read(read_data), 4);
         assert_eq!(&read_data[0..4], &write_data[7..11]);
     }
 
@@ -411,9 +704,7 @@ mod tests {
 
         assert!(ruffer.write(write_data).is_ok());
         ruffer.resize(32);
-        let res = ruffer.read(read_data);
-        assert!(res.is_ok());
-        assert_eq!(res.unwrap(), 11);
+        assert_eq!(ruffer.read(read_data), 11);
         assert_eq!(&read_data[0..11], &write_data[0..11]);
     }
 
@@ -431,6 +722,187 @@ mod tests {
         assert_eq!(res.unwrap(), 5);
         let res = ruffer.write(write_data);
         assert!(res.is_err());
-        assert_eq!(res.unwrap_err().kind(), std::io::ErrorKind::WouldBlock);
+        assert_eq!(res.unwrap_err(), Error::WouldBlock);
+    }
+
+    #[test]
+    fn as_slices_no_wrap() {
+        let mut ruffer = RingBuffer::with_capacity(16);
+        let write_data = "data".as_bytes();
+        assert!(ruffer.write(write_data).is_ok());
+        let (first, second) = ruffer.as_slices();
+        assert_eq!(first, write_data);
+        assert!(second.is_empty());
+    }
+
+    #[test]
+    fn as_slices_wrapped() {
+        let mut ruffer = RingBuffer::with_capacity(4);
+        let write_data = "data123".as_bytes();
+        assert!(ruffer.write(write_data).is_ok());
+        let (first, second) = ruffer.as_slices();
+        let mut joined = first.to_vec();
+        joined.extend_from_slice(second);
+        assert_eq!(joined, write_data[3..7].to_vec());
+    }
+
+    #[test]
+    fn free_slices_and_advance_write_no_wrap() {
+        let mut ruffer: RingBuffer<u8> = RingBuffer::with_capacity(16);
+        ruffer.set_overwrite(false);
+        let write_data = "data".as_bytes();
+
+        let (first, second) = ruffer.free_slices();
+        assert_eq!(first.len() + second.len(), 16);
+        first[..write_data.len()].copy_from_slice(write_data);
+        assert_eq!(ruffer.advance_write(write_data.len()), write_data.len());
+        assert_eq!(ruffer.len(), write_data.len());
+        assert_eq!(ruffer.to_vec(), write_data.to_vec());
+    }
+
+    #[test]
+    fn free_slices_wrapped_and_clamped() {
+        let mut ruffer: RingBuffer<u8> = RingBuffer::with_capacity(4);
+        assert!(ruffer.write(&[1, 2, 3]).is_ok());
+        assert_eq!(ruffer.pop(1), 1);
+
+        let (first, second) = ruffer.free_slices();
+        assert_eq!(first.len() + second.len(), 2);
+        assert_eq!(ruffer.advance_write(10), 2);
+        assert_eq!(ruffer.len(), 4);
+    }
+
+    #[test]
+    fn consume_is_pop() {
+        let mut ruffer = RingBuffer::with_capacity(16);
+        let write_data = "data".as_bytes();
+        assert!(ruffer.write(write_data).is_ok());
+        assert_eq!(ruffer.consume(2), 2);
+        assert_eq!(ruffer.len(), 2);
+        assert_eq!(ruffer.to_vec(), write_data[2..4].to_vec());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn bufread_fill_buf_and_consume() {
+        let mut ruffer = RingBuffer::with_capacity(16);
+        let write_data = "data".as_bytes();
+        assert!(ruffer.write(write_data).is_ok());
+
+        assert_eq!(BufRead::fill_buf(&mut ruffer).unwrap(), write_data);
+        BufRead::consume(&mut ruffer, 2);
+        assert_eq!(ruffer.len(), 2);
+        assert_eq!(BufRead::fill_buf(&mut ruffer).unwrap(), &write_data[2..4]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn io_copy_drains_buffer() {
+        let mut ruffer = RingBuffer::with_capacity(16);
+        let write_data = "thisisatest".as_bytes();
+        assert!(ruffer.write(write_data).is_ok());
+
+        let mut sink = Vec::new();
+        let copied = std::io::copy(&mut ruffer, &mut sink).unwrap();
+        assert_eq!(copied as usize, write_data.len());
+        assert_eq!(sink, write_data);
+        assert!(ruffer.empty());
+    }
+
+    #[test]
+    fn limits_reports_occupancy_and_free_space() {
+        let mut ruffer = RingBuffer::with_capacity(16);
+        assert!(ruffer.write(&[1u8; 5]).is_ok());
+        let limits = ruffer.limits();
+        assert_eq!(limits.occupied, 5);
+        assert_eq!(limits.free, 11);
+        assert_eq!(limits.capacity, 16);
+    }
+
+    #[test]
+    fn target_capacity_defaults_to_capacity() {
+        let ruffer: RingBuffer<u8> = RingBuffer::with_capacity(8);
+        assert_eq!(ruffer.target_capacity(), 8);
+    }
+
+    #[test]
+    fn write_grows_toward_target_capacity_under_non_overwrite() {
+        let mut ruffer: RingBuffer<u8> = RingBuffer::with_capacity(4);
+        ruffer.set_overwrite(false);
+        ruffer.set_target_capacity(16);
+
+        let write_data = [1u8; 10];
+        let res = ruffer.write(&write_data);
+        assert_eq!(res.unwrap(), 10);
+        assert_eq!(ruffer.capacity(), 10);
+        assert_eq!(ruffer.len(), 10);
+    }
+
+    #[test]
+    fn write_still_blocks_once_target_capacity_is_exhausted() {
+        let mut ruffer: RingBuffer<u8> = RingBuffer::with_capacity(4);
+        ruffer.set_overwrite(false);
+        ruffer.set_target_capacity(8);
+
+        assert_eq!(ruffer.write(&[1u8; 8]).unwrap(), 8);
+        assert_eq!(ruffer.capacity(), 8);
+        let res = ruffer.write(&[1u8]);
+        assert_eq!(res.unwrap_err(), Error::WouldBlock);
+    }
+
+    #[test]
+    fn shrink_to_target_releases_grown_capacity_once_drained() {
+        let mut ruffer: RingBuffer<u8> = RingBuffer::with_capacity(4);
+        ruffer.set_overwrite(false);
+        ruffer.set_target_capacity(4);
+
+        assert_eq!(ruffer.write(&[1u8; 4]).unwrap(), 4);
+        ruffer.set_target_capacity(16);
+        assert_eq!(ruffer.write(&[2u8; 8]).unwrap(), 8);
+        assert_eq!(ruffer.capacity(), 12);
+
+        ruffer.set_target_capacity(4);
+        assert!(!ruffer.shrink_to_target());
+        assert_eq!(ruffer.pop(12), 12);
+        assert!(ruffer.shrink_to_target());
+        assert_eq!(ruffer.capacity(), 4);
+    }
+
+    #[test]
+    fn shrink_to_target_with_zero_target_does_not_panic() {
+        let mut ruffer: RingBuffer<u8> = RingBuffer::with_capacity(4);
+        ruffer.set_target_capacity(0);
+        assert!(ruffer.shrink_to_target());
+        assert_eq!(ruffer.capacity(), 0);
+        assert_eq!(ruffer.len(), 0);
+    }
+
+    #[test]
+    fn write_after_zero_target_shrink_does_not_panic() {
+        let mut ruffer: RingBuffer<u8> = RingBuffer::with_capacity(4);
+        ruffer.set_target_capacity(0);
+        assert!(ruffer.shrink_to_target());
+        assert_eq!(ruffer.write(&[1, 2, 3]).unwrap(), 0);
+        assert_eq!(ruffer.len(), 0);
+    }
+
+    #[test]
+    fn pop_after_zero_target_shrink_does_not_panic() {
+        let mut ruffer: RingBuffer<u8> = RingBuffer::with_capacity(4);
+        ruffer.set_target_capacity(0);
+        assert!(ruffer.shrink_to_target());
+        assert_eq!(ruffer.pop(0), 0);
+        assert_eq!(ruffer.pop(1), 0);
+        assert_eq!(ruffer.len(), 0);
+    }
+
+    #[test]
+    fn advance_write_after_zero_target_shrink_does_not_panic() {
+        let mut ruffer: RingBuffer<u8> = RingBuffer::with_capacity(4);
+        ruffer.set_target_capacity(0);
+        assert!(ruffer.shrink_to_target());
+        assert_eq!(ruffer.advance_write(0), 0);
+        assert_eq!(ruffer.advance_write(1), 0);
+        assert_eq!(ruffer.len(), 0);
     }
 }
@@ -0,0 +1,261 @@
+//! A lock-free single-producer/single-consumer split of the RingBuffer.
+//! `RingBuffer::split` hands back a `Producer` and a `Consumer` which share the same
+//! backing allocation through an `Arc`, so a writer thread and a reader thread can
+//! operate concurrently without ever taking a lock.
+//!
+//! The producer only ever advances `head` and the consumer only ever advances `tail`,
+//! so each side publishes its progress with a `Release` store and observes the other
+//! side's progress with an `Acquire` load. This guarantees a byte is never read until
+//! the store that wrote it has become visible.
+//!
+//! Overwriting is not supported in this mode: since advancing `tail` is the consumer's
+//! exclusive responsibility, a producer that encounters a full buffer has no way to
+//! safely reclaim space and instead returns `WouldBlock`.
+
+use std::{
+    cell::UnsafeCell,
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+struct Inner<T> {
+    buffer: UnsafeCell<Vec<T>>,
+    capacity: usize,
+    head: AtomicUsize,
+    tail: AtomicUsize,
+}
+
+// Safety: `head` is only ever written by the `Producer` and `tail` is only ever
+// written by the `Consumer`, so the two sides never touch overlapping regions of
+// `buffer` at the same time.
+unsafe impl<T: Send> Sync for Inner<T> {}
+
+/// The write half of a split RingBuffer.
+///
+/// A `Producer` is `Send` but not `Sync` and not `Clone`; there is exactly one
+/// writer, and `!Sync` stops that one writer from being called concurrently
+/// through a shared `&Producer` handed to multiple threads.
+pub struct Producer<T> {
+    inner: Arc<Inner<T>>,
+    // `Inner<T>` is `Sync` so `Arc<Inner<T>>` alone would make this `Sync` too,
+    // which would let two threads call `write` through the same `&Producer`.
+    // `write`'s safety argument assumes a single writer, so block that with a
+    // `!Sync` marker field.
+    _not_sync: PhantomData<UnsafeCell<()>>,
+}
+
+/// The read half of a split RingBuffer.
+///
+/// A `Consumer` is `Send` but not `Sync` and not `Clone`; there is exactly one
+/// reader, and `!Sync` stops that one reader from being called concurrently
+/// through a shared `&Consumer` handed to multiple threads.
+pub struct Consumer<T> {
+    inner: Arc<Inner<T>>,
+    _not_sync: PhantomData<UnsafeCell<()>>,
+}
+
+impl<T: Copy> Producer<T> {
+    /// Write as much of `buf` as there is free space for.
+    ///
+    /// # Returns
+    /// The number of elements written, or `WouldBlock` if the buffer is full.
+    pub fn write(&self, buf: &[T]) -> std::io::Result<usize> {
+        let inner = self.inner.as_ref();
+        let capacity = inner.capacity;
+        if capacity == 0 {
+            return Err(std::io::ErrorKind::WouldBlock.into());
+        }
+        let tail = inner.tail.load(Ordering::Acquire);
+        let mut head = inner.head.load(Ordering::Relaxed);
+
+        let free = if head >= tail {
+            capacity - (head - tail) - 1
+        } else {
+            tail - head - 1
+        };
+        if free == 0 {
+            return Err(std::io::ErrorKind::WouldBlock.into());
+        }
+
+        let num_elements = std::cmp::min(free, buf.len());
+        // Safety: only the producer writes through this pointer, and only at
+        // indices the consumer cannot yet observe (they are published below).
+        let buffer = unsafe { &mut *inner.buffer.get() };
+        for element in buf.iter().take(num_elements) {
+            buffer[head] = *element;
+            head = (head + 1) % capacity;
+        }
+        inner.head.store(head, Ordering::Release);
+        Ok(num_elements)
+    }
+
+    /// Acquire the capacity of the underlying buffer.
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity
+    }
+}
+
+impl<T: Copy> Consumer<T> {
+    /// Read as many elements as are available into `buf`.
+    ///
+    /// # Returns
+    /// The number of elements read. `0` if the buffer is empty.
+    pub fn read(&self, buf: &mut [T]) -> std::io::Result<usize> {
+        let inner = self.inner.as_ref();
+        let capacity = inner.capacity;
+        let head = inner.head.load(Ordering::Acquire);
+        let mut tail = inner.tail.load(Ordering::Relaxed);
+
+        let available = if head >= tail {
+            head - tail
+        } else {
+            capacity - tail + head
+        };
+        let num_elements = std::cmp::min(available, buf.len());
+
+        // Safety: the consumer only reads through this pointer, and only at
+        // indices the producer has already published via the `head` store.
+        let buffer = unsafe { &*inner.buffer.get() };
+        for slot in buf.iter_mut().take(num_elements) {
+            *slot = buffer[tail];
+            tail = (tail + 1) % capacity;
+        }
+        inner.tail.store(tail, Ordering::Release);
+        Ok(num_elements)
+    }
+
+    /// Acquire the capacity of the underlying buffer.
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity
+    }
+}
+
+impl<T: Copy + Default> crate::RingBuffer<T> {
+    /// Split the RingBuffer into an independently-ownable `Producer`/`Consumer`
+    /// pair sharing the same backing allocation.
+    ///
+    /// Overwriting is disabled for the lifetime of the split, since only the
+    /// `Consumer` may advance `tail`. A `Producer::write` on a full buffer
+    /// returns `WouldBlock` rather than overwriting unread data.
+    ///
+    /// A `Producer`/`Consumer` pair distinguishes full from empty by always holding one
+    /// slot back (`head == tail` means empty), so if `self` is already completely full
+    /// there is no room left to represent that. The oldest unread element is dropped to
+    /// free that slot; after the split, a `Producer::write` never drops data and returns
+    /// `WouldBlock` instead. A zero-capacity `self` is trivially "full" by
+    /// this check too, but [`RingBuffer::pop`] is a no-op on a zero-capacity buffer, so
+    /// there is simply nothing to drop and the resulting `Producer`/`Consumer` share an
+    /// empty, zero-capacity buffer.
+    pub fn split(mut self) -> (Producer<T>, Consumer<T>) {
+        if self.len == self.capacity {
+            self.pop(1);
+        }
+        let capacity = self.capacity;
+        let inner = Arc::new(Inner {
+            buffer: UnsafeCell::new(self.buffer),
+            capacity,
+            head: AtomicUsize::new(self.head),
+            tail: AtomicUsize::new(self.tail),
+        });
+        (
+            Producer {
+                inner: inner.clone(),
+                _not_sync: PhantomData,
+            },
+            Consumer {
+                inner,
+                _not_sync: PhantomData,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RingBuffer;
+
+    #[test]
+    fn split_write_then_read() {
+        let ruffer: RingBuffer<u8> = RingBuffer::with_capacity(16);
+        let (producer, consumer) = ruffer.split();
+
+        let write_data = "hello".as_bytes();
+        assert_eq!(producer.write(write_data).unwrap(), write_data.len());
+
+        let read_data = &mut [0u8; 16];
+        let read = consumer.read(read_data).unwrap();
+        assert_eq!(read, write_data.len());
+        assert_eq!(&read_data[0..read], write_data);
+    }
+
+    #[test]
+    fn split_write_full_would_block() {
+        let ruffer: RingBuffer<u8> = RingBuffer::with_capacity(4);
+        let (producer, _consumer) = ruffer.split();
+
+        // One slot is always held back to disambiguate full vs empty.
+        assert_eq!(producer.write(&[1, 2, 3]).unwrap(), 3);
+        assert_eq!(
+            producer.write(&[4]).unwrap_err().kind(),
+            std::io::ErrorKind::WouldBlock
+        );
+    }
+
+    #[test]
+    fn split_drops_oldest_byte_when_prefilled_to_capacity() {
+        let mut ruffer: RingBuffer<u8> = RingBuffer::with_capacity(4);
+        assert!(ruffer.write(&[1, 2, 3, 4]).is_ok());
+        let (producer, consumer) = ruffer.split();
+
+        // The oldest byte (1) is dropped to free the disambiguation slot; the rest
+        // of the pre-filled data survives the split uncorrupted.
+        let read_data = &mut [0u8; 4];
+        let read = consumer.read(read_data).unwrap();
+        assert_eq!(read, 3);
+        assert_eq!(&read_data[0..read], &[2, 3, 4]);
+
+        // The freed slot is immediately usable by the producer.
+        assert_eq!(producer.write(&[5]).unwrap(), 1);
+    }
+
+    #[test]
+    fn split_across_threads() {
+        let ruffer: RingBuffer<u8> = RingBuffer::with_capacity(64);
+        let (producer, consumer) = ruffer.split();
+
+        let writer = std::thread::spawn(move || {
+            for _ in 0..100 {
+                while producer.write(&[42]).is_err() {
+                    std::thread::yield_now();
+                }
+            }
+        });
+
+        let mut total = 0;
+        while total < 100 {
+            let mut buf = [0u8; 8];
+            let n = consumer.read(&mut buf).unwrap();
+            for byte in &buf[0..n] {
+                assert_eq!(*byte, 42);
+            }
+            total += n;
+        }
+        writer.join().unwrap();
+    }
+
+    #[test]
+    fn split_of_zero_capacity_buffer_does_not_panic() {
+        let ruffer: RingBuffer<u8> = RingBuffer::with_capacity(0);
+        let (producer, consumer) = ruffer.split();
+
+        assert_eq!(producer.capacity(), 0);
+        assert_eq!(
+            producer.write(&[1]).unwrap_err().kind(),
+            std::io::ErrorKind::WouldBlock
+        );
+        assert_eq!(consumer.read(&mut [0u8; 1]).unwrap(), 0);
+    }
+}
@@ -2,14 +2,11 @@
 //! This is just a helper struct which has a similar API and wraps the RingBuffer in a Mutex.
 //! Utilize an std::sync::Arc to make a RingBuffer which is Send + Sync.
 
-use crate::{RingBuffer, DEFAULT_CAPACITY};
-use std::{
-    io::{Read as _, Write as _},
-    sync::Mutex,
-};
+use crate::{Error, Limits, RingBuffer, DEFAULT_CAPACITY};
+use std::sync::Mutex;
 
 pub struct SyncRingBuffer {
-    buffer: Mutex<RingBuffer>,
+    buffer: Mutex<RingBuffer<u8>>,
 }
 
 // Static Impls
@@ -25,7 +22,16 @@ impl SyncRingBuffer {
     }
 }
 
+impl Default for SyncRingBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // Member Impls
+// `empty()` below is this type's `is_empty()`, matching `RingBuffer`; renaming it would be a
+// breaking API change, so the lint is silenced instead.
+#[allow(clippy::len_without_is_empty)]
 impl SyncRingBuffer {
     pub fn capacity(&self) -> usize {
         let buffer = self.buffer.lock().unwrap();
@@ -47,9 +53,9 @@ impl SyncRingBuffer {
         buffer.to_vec()
     }
 
-    pub fn pop_bytes(&self, num: usize) -> usize {
+    pub fn pop(&self, num: usize) -> usize {
         let mut buffer = self.buffer.lock().unwrap();
-        buffer.pop_bytes(num)
+        buffer.pop(num)
     }
 
     pub fn resize(&self, new_size: usize) {
@@ -57,12 +63,32 @@ impl SyncRingBuffer {
         buffer.resize(new_size);
     }
 
-    pub fn write(&self, buf: &[u8]) -> std::io::Result<usize> {
+    pub fn limits(&self) -> Limits {
+        let buffer = self.buffer.lock().unwrap();
+        buffer.limits()
+    }
+
+    pub fn target_capacity(&self) -> usize {
+        let buffer = self.buffer.lock().unwrap();
+        buffer.target_capacity()
+    }
+
+    pub fn set_target_capacity(&self, target: usize) {
+        let mut buffer = self.buffer.lock().unwrap();
+        buffer.set_target_capacity(target);
+    }
+
+    pub fn shrink_to_target(&self) -> bool {
+        let mut buffer = self.buffer.lock().unwrap();
+        buffer.shrink_to_target()
+    }
+
+    pub fn write(&self, buf: &[u8]) -> Result<usize, Error> {
         let mut buffer = self.buffer.lock().unwrap();
         buffer.write(buf)
     }
 
-    pub fn read(&self, buf: &mut [u8]) -> std::io::Result<usize> {
+    pub fn read(&self, buf: &mut [u8]) -> usize {
         let mut buffer = self.buffer.lock().unwrap();
         buffer.read(buf)
     }
@@ -75,7 +101,7 @@ mod tests {
     #[test]
     fn create_sync_ringbuffer_with_capacity() {
         let ruffer = SyncRingBuffer::with_capacity(1024);
-        assert_eq!(ruffer.empty(), true);
+        assert!(ruffer.empty());
         assert_eq!(ruffer.len(), 0);
         assert_eq!(ruffer.capacity(), 1024);
     }
@@ -87,7 +113,7 @@ mod tests {
         let read_data = &mut [0u8; 4];
 
         assert!(ruffer.write(write_data).is_ok());
-        assert!(ruffer.read(read_data).is_ok());
+        assert_eq!(ruffer.read(read_data), 4);
         assert_eq!(read_data, &write_data[49..])
     }
 }